@@ -0,0 +1,42 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::ReactorHandle;
+use async_io::Async;
+use core::task::{Context, Poll};
+use std::{
+    io,
+    os::fd::{AsFd, BorrowedFd, RawFd},
+};
+
+/// Borrows a raw fd for registration with `async_io::Async` without taking ownership of it,
+/// so the reactor never closes an fd that `socket::Fd` still owns
+struct BorrowedRawFd(RawFd);
+
+impl AsFd for BorrowedRawFd {
+    #[inline]
+    fn as_fd(&self) -> BorrowedFd<'_> {
+        // SAFETY: the fd outlives this `BorrowedRawFd`, which is only ever held behind the
+        // `Async` wrapper for the lifetime of the owning `Handle`
+        unsafe { BorrowedFd::borrow_raw(self.0) }
+    }
+}
+
+/// A [`ReactorHandle`] backed by the `async-io` (smol) reactor, for runtimes that don't use
+/// tokio
+pub struct Handle(Async<BorrowedRawFd>);
+
+impl Handle {
+    /// Registers `fd` for write-readiness with the `async-io` reactor, without taking
+    /// ownership of it
+    pub fn new(fd: RawFd) -> io::Result<Self> {
+        Async::new(BorrowedRawFd(fd)).map(Self)
+    }
+}
+
+impl ReactorHandle for Handle {
+    #[inline]
+    fn poll_write_ready(&mut self, cx: &mut Context) -> Poll<()> {
+        self.0.poll_writable(cx).map(|_| ())
+    }
+}
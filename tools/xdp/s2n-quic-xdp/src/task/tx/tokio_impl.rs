@@ -0,0 +1,33 @@
+// Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::ReactorHandle;
+use core::task::{Context, Poll};
+use std::{io, os::unix::io::RawFd};
+use tokio::io::{unix::AsyncFd, Interest};
+
+/// A [`ReactorHandle`] backed by tokio's I/O driver
+pub struct Handle(AsyncFd<RawFd>);
+
+impl Handle {
+    /// Registers `fd` for write-readiness with tokio's I/O driver
+    pub fn new(fd: RawFd) -> io::Result<Self> {
+        AsyncFd::with_interest(fd, Interest::WRITABLE).map(Self)
+    }
+}
+
+impl ReactorHandle for Handle {
+    #[inline]
+    fn poll_write_ready(&mut self, cx: &mut Context) -> Poll<()> {
+        match self.0.poll_write_ready(cx) {
+            Poll::Ready(Ok(mut guard)) => {
+                guard.clear_ready();
+                Poll::Ready(())
+            }
+            // the fd was closed or otherwise errored out; treat it as ready so the caller can
+            // observe the error on its next syscall rather than sleeping forever
+            Poll::Ready(Err(_)) => Poll::Ready(()),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}